@@ -1,20 +1,165 @@
 //! Add theme animations to widgets.
 
-use iced_core::Color;
+use super::time::{Duration, Instant};
+use iced_core::{Color, Padding, Size};
 
-/// Hover animation of the widget
+/// A value that can be linearly interpolated between two instances of itself.
+///
+/// This backs [`Animation<T>`], letting a single animation drive colors, geometry, or plain
+/// floats through the same easing code path.
+pub trait Lerp {
+    /// Interpolate between `self` and `other`, where `t` is normalized progress, usually in
+    /// `[0, 1]`; [`Animation<T>`] passes values slightly outside it for overshooting effects.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        mix(self, other, t)
+    }
+}
+
+impl Lerp for Size {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Size::new(
+            self.width.lerp(other.width, t),
+            self.height.lerp(other.height, t),
+        )
+    }
+}
+
+impl Lerp for Padding {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Padding {
+            top: self.top.lerp(other.top, t),
+            right: self.right.lerp(other.right, t),
+            bottom: self.bottom.lerp(other.bottom, t),
+            left: self.left.lerp(other.left, t),
+        }
+    }
+}
+
+/// Ease `elapsed_ms` (time since a transition started) into a progress delta, waiting out
+/// `delay_ms` first. The delta goes from `0.0` to `1.0`; back and elastic effects overshoot
+/// that range in between (see [`AnimationEffect::apply`]).
+///
+/// Every animation primitive in this module (`Animation<T>`, `HoverPressedAnimation`,
+/// `HoldToConfirm`) goes through this single function to turn elapsed time into eased progress,
+/// so their easing math can't silently drift apart from one another.
+fn eased_delta(effect: AnimationEffect, elapsed_ms: u32, delay_ms: u32, duration_ms: u32) -> f32 {
+    let delayed_elapsed_ms = elapsed_ms.saturating_sub(delay_ms);
+    effect.apply(delayed_elapsed_ms as f32 / (duration_ms as f32))
+}
+
+/// A generic animation that transitions a [`Lerp`] value of type `T` from `from` to `to`.
+///
+/// Unlike [`HoverPressedAnimation`], which only ever tracks a scalar progress, this drives the
+/// value directly, so widgets can animate colors, corner radii, spacing, and geometry without
+/// re-deriving them by hand from a raw progress float.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Animation<T> {
+    /// Animation direction: forward means it goes from `from` to `to`.
+    pub direction: AnimationDirection,
+    /// The instant the animation was started at (`None` if it is not running)
+    pub started_at: Option<Instant>,
+    /// The value the animation starts from.
+    pub from: T,
+    /// The value the animation is moving towards.
+    pub to: T,
+    /// Duration of the transition, in milliseconds.
+    pub duration_ms: u32,
+    /// The type of effect for the animation.
+    pub effect: AnimationEffect,
+}
+
+impl<T: Lerp + Copy> Animation<T> {
+    /// Create and immediately start an animation transitioning from `from` to `to`.
+    pub fn new(from: T, to: T, duration_ms: u32, effect: AnimationEffect, now: Instant) -> Self {
+        Self {
+            direction: AnimationDirection::Forward,
+            started_at: Some(now),
+            from,
+            to,
+            duration_ms,
+            effect,
+        }
+    }
+
+    /// Evaluate the interpolated value at `now`.
+    pub fn value(&self, now: Instant) -> T {
+        let Some(started_at) = self.started_at else {
+            return self.to;
+        };
+        if self.effect == AnimationEffect::None || self.duration_ms == 0 {
+            return self.to;
+        }
+        let elapsed_ms = (now - started_at).as_millis() as u32;
+        let eased = eased_delta(self.effect, elapsed_ms, 0, self.duration_ms);
+        self.from.lerp(self.to, eased)
+    }
+
+    /// Whether the animation has reached `to` yet.
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.started_at.map_or(true, |started_at| {
+            self.effect == AnimationEffect::None
+                || self.duration_ms == 0
+                || (now - started_at).as_millis() as f32 >= self.duration_ms as f32
+        })
+    }
+
+    /// Retarget the animation mid-flight: snap `from` to the current interpolated value and
+    /// restart the clock towards `target`, so retargeting stays smooth instead of jumping.
+    pub fn ease_to(&mut self, target: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = target;
+        self.started_at = Some(now);
+    }
+}
+
+/// Hover animation of the widget.
+///
+/// A scalar-progress animation that is separate from [`Animation<f32>`]: it does not wrap or
+/// contain one, but uses the same [`AnimationDirection`] and [`AnimationEffect`] easing, and also
+/// knows how to reverse itself mid-flight in response to the cursor entering or leaving the
+/// widget.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct HoverPressedAnimation {
     /// Animation direction: forward means it goes from non-hovered to hovered state
     pub direction: AnimationDirection,
     /// The instant the animation was started at (`None` if it is not running)
-    pub started_at: Option<std::time::Instant>,
+    pub started_at: Option<Instant>,
     /// The progress of the animationn, between 0.0 and 1.0
     pub animation_progress: f32,
     /// The progress the animation has been started at
     pub initial_progress: f32,
     /// The type of effect for the animation
     pub effect: AnimationEffect,
+    /// The current lifecycle state of the animation
+    pub state: AnimationState,
+    /// Milliseconds to wait after starting a forward transition before progress advances
+    pub forward_delay_ms: u32,
+    /// Milliseconds to wait after starting a backward transition before progress advances
+    pub backward_delay_ms: u32,
+}
+
+/// The lifecycle state of a [`HoverPressedAnimation`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AnimationState {
+    /// The animation has never been started, or has fully reset to its initial value.
+    #[default]
+    Idle,
+    /// The animation is actively progressing towards its target.
+    Running,
+    /// The animation was paused mid-flight and is holding its current progress.
+    Paused,
+    /// The animation has reached its terminal value for the current direction.
+    Completed,
 }
 
 /// The type of effect for the animation
@@ -27,6 +172,218 @@ pub enum AnimationEffect {
     EaseOut,
     /// Transistion is instantaneous.
     None,
+    /// Quadratic ease in.
+    EaseInQuad,
+    /// Quadratic ease out.
+    EaseOutQuad,
+    /// Quadratic ease in and out.
+    EaseInOutQuad,
+    /// Cubic ease in.
+    EaseInCubic,
+    /// Cubic ease out.
+    EaseOutCubic,
+    /// Cubic ease in and out.
+    EaseInOutCubic,
+    /// Quartic ease in.
+    EaseInQuart,
+    /// Quartic ease out.
+    EaseOutQuart,
+    /// Quartic ease in and out.
+    EaseInOutQuart,
+    /// Quintic ease in.
+    EaseInQuint,
+    /// Quintic ease out.
+    EaseOutQuint,
+    /// Quintic ease in and out.
+    EaseInOutQuint,
+    /// Sine ease in.
+    EaseInSine,
+    /// Sine ease out.
+    EaseOutSine,
+    /// Sine ease in and out.
+    EaseInOutSine,
+    /// Exponential ease in.
+    EaseInExpo,
+    /// Exponential ease out.
+    EaseOutExpo,
+    /// Exponential ease in and out.
+    EaseInOutExpo,
+    /// Circular ease in.
+    EaseInCirc,
+    /// Circular ease out.
+    EaseOutCirc,
+    /// Circular ease in and out.
+    EaseInOutCirc,
+    /// Back (overshoot) ease in.
+    EaseInBack,
+    /// Back (overshoot) ease out.
+    EaseOutBack,
+    /// Back (overshoot) ease in and out.
+    EaseInOutBack,
+    /// Elastic ease in.
+    EaseInElastic,
+    /// Elastic ease out.
+    EaseOutElastic,
+    /// Elastic ease in and out.
+    EaseInOutElastic,
+    /// Bounce ease in.
+    EaseInBounce,
+    /// Bounce ease out.
+    EaseOutBounce,
+    /// Bounce ease in and out.
+    EaseInOutBounce,
+}
+
+impl AnimationEffect {
+    /// Ease a normalized progress value `t` in `[0, 1]`, returning the eased value.
+    ///
+    /// The result is `0.0` at `t = 0` and `1.0` at `t = 1`. In between it stays in `[0, 1]`,
+    /// except for the back and elastic effects, which overshoot below `0.0` or above `1.0`.
+    ///
+    /// `AnimationEffect::None` is meant to be instantaneous, so callers should special-case it
+    /// and skip interpolation entirely rather than relying on this function: its `None` arm just
+    /// returns `t` unmodified, which is only correct by accident (identical to `Linear`).
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            AnimationEffect::Linear => t,
+            AnimationEffect::EaseOut | AnimationEffect::EaseOutCubic => ease_out_cubic(t),
+            AnimationEffect::None => t,
+            AnimationEffect::EaseInQuad => t * t,
+            AnimationEffect::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            AnimationEffect::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            AnimationEffect::EaseInCubic => t.powi(3),
+            AnimationEffect::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            AnimationEffect::EaseInQuart => t.powi(4),
+            AnimationEffect::EaseOutQuart => 1.0 - (1.0 - t).powi(4),
+            AnimationEffect::EaseInOutQuart => {
+                if t < 0.5 {
+                    8.0 * t.powi(4)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(4) / 2.0
+                }
+            }
+            AnimationEffect::EaseInQuint => t.powi(5),
+            AnimationEffect::EaseOutQuint => 1.0 - (1.0 - t).powi(5),
+            AnimationEffect::EaseInOutQuint => {
+                if t < 0.5 {
+                    16.0 * t.powi(5)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(5) / 2.0
+                }
+            }
+            AnimationEffect::EaseInSine => 1.0 - (t * std::f32::consts::FRAC_PI_2).cos(),
+            AnimationEffect::EaseOutSine => (t * std::f32::consts::FRAC_PI_2).sin(),
+            AnimationEffect::EaseInOutSine => -((std::f32::consts::PI * t).cos() - 1.0) / 2.0,
+            AnimationEffect::EaseInExpo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2f32.powf(10.0 * t - 10.0)
+                }
+            }
+            AnimationEffect::EaseOutExpo => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2f32.powf(-10.0 * t)
+                }
+            }
+            AnimationEffect::EaseInOutExpo => {
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else if t < 0.5 {
+                    2f32.powf(20.0 * t - 10.0) / 2.0
+                } else {
+                    (2.0 - 2f32.powf(-20.0 * t + 10.0)) / 2.0
+                }
+            }
+            AnimationEffect::EaseInCirc => 1.0 - (1.0 - t.powi(2)).sqrt(),
+            AnimationEffect::EaseOutCirc => (1.0 - (t - 1.0).powi(2)).sqrt(),
+            AnimationEffect::EaseInOutCirc => {
+                if t < 0.5 {
+                    (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
+                } else {
+                    ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+                }
+            }
+            AnimationEffect::EaseInBack => {
+                const S: f32 = 1.70158;
+                t * t * ((S + 1.0) * t - S)
+            }
+            AnimationEffect::EaseOutBack => {
+                const S: f32 = 1.70158;
+                let p = t - 1.0;
+                1.0 + p * p * ((S + 1.0) * p + S)
+            }
+            AnimationEffect::EaseInOutBack => {
+                const S: f32 = 1.70158 * 1.525;
+                if t < 0.5 {
+                    ((2.0 * t).powi(2) * ((S + 1.0) * 2.0 * t - S)) / 2.0
+                } else {
+                    let p = 2.0 * t - 2.0;
+                    (p * p * ((S + 1.0) * p + S) + 2.0) / 2.0
+                }
+            }
+            AnimationEffect::EaseInElastic => {
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else {
+                    let c4 = (2.0 * std::f32::consts::PI) / 3.0;
+                    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * c4).sin()
+                }
+            }
+            AnimationEffect::EaseOutElastic => {
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else {
+                    let c4 = (2.0 * std::f32::consts::PI) / 3.0;
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * c4).sin() + 1.0
+                }
+            }
+            AnimationEffect::EaseInOutElastic => {
+                if t == 0.0 {
+                    0.0
+                } else if t == 1.0 {
+                    1.0
+                } else {
+                    let c5 = (2.0 * std::f32::consts::PI) / 4.5;
+                    if t < 0.5 {
+                        -(2f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * c5).sin()) / 2.0
+                    } else {
+                        (2f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * c5).sin()) / 2.0 + 1.0
+                    }
+                }
+            }
+            AnimationEffect::EaseInBounce => 1.0 - ease_out_bounce(1.0 - t),
+            AnimationEffect::EaseOutBounce => ease_out_bounce(t),
+            AnimationEffect::EaseInOutBounce => {
+                if t < 0.5 {
+                    (1.0 - ease_out_bounce(1.0 - 2.0 * t)) / 2.0
+                } else {
+                    (1.0 + ease_out_bounce(2.0 * t - 1.0)) / 2.0
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -48,6 +405,16 @@ impl HoverPressedAnimation {
         }
     }
 
+    /// Set a delay to wait out, per direction, before progress starts advancing.
+    ///
+    /// Useful for tooltip-style hover reveals that should ignore quick cursor pass-throughs.
+    #[must_use]
+    pub fn with_delays(mut self, forward_delay_ms: u32, backward_delay_ms: u32) -> Self {
+        self.forward_delay_ms = forward_delay_ms;
+        self.backward_delay_ms = backward_delay_ms;
+        self
+    }
+
     /// Check if the animation is running
     pub fn is_running(&self) -> bool {
         self.started_at.is_some()
@@ -59,6 +426,30 @@ impl HoverPressedAnimation {
         self.started_at = None;
         self.animation_progress = 0.0;
         self.initial_progress = 0.0;
+        self.state = AnimationState::Idle;
+    }
+
+    /// Pause the animation, freezing its current progress until [`Self::resume`] is called.
+    ///
+    /// Does nothing if the animation isn't currently running.
+    pub fn pause(&mut self, _now: Instant) {
+        if self.state != AnimationState::Running {
+            return;
+        }
+        self.initial_progress = self.animation_progress;
+        self.started_at = None;
+        self.state = AnimationState::Paused;
+    }
+
+    /// Resume a previously paused animation from wherever it was frozen.
+    ///
+    /// Does nothing if the animation isn't currently paused.
+    pub fn resume(&mut self, now: Instant) {
+        if self.state != AnimationState::Paused {
+            return;
+        }
+        self.started_at = Some(now);
+        self.state = AnimationState::Running;
     }
 
     /// Update the animation progress, if necessary, and returns the need to request a redraw.
@@ -66,56 +457,54 @@ impl HoverPressedAnimation {
         &mut self,
         forward_duration_ms: u32,
         backward_duration_ms: u32,
-        now: std::time::Instant,
+        now: Instant,
     ) -> bool {
         // Is the animation running ?
         if let Some(started_at) = self.started_at {
-            if forward_duration_ms == 0 {
-                self.animation_progress = 1.0;
+            let duration_ms = match self.direction {
+                AnimationDirection::Forward => forward_duration_ms,
+                AnimationDirection::Backward => backward_duration_ms,
+            };
+
+            if duration_ms == 0 {
+                // Instantaneous: jump straight to the terminal value for this direction instead
+                // of falling through to `eased_delta`, which would divide by a zero duration.
+                self.animation_progress = match self.direction {
+                    AnimationDirection::Forward => 1.0,
+                    AnimationDirection::Backward => 0.0,
+                };
             }
 
             // Reset the animation once it has gone forward and now fully backward
             if self.animation_progress == 0.0 && self.direction == AnimationDirection::Backward {
                 self.started_at = None;
-            } else {
-                // Evaluate new progress
-                match &mut self.effect {
-                    AnimationEffect::Linear => match self.direction {
-                        AnimationDirection::Forward => {
-                            self.animation_progress = (self.initial_progress
-                                + (((now - started_at).as_millis() as f64)
-                                    / (forward_duration_ms as f64))
-                                    as f32)
-                                .clamp(0.0, 1.0);
-                        }
-                        AnimationDirection::Backward => {
-                            self.animation_progress = (self.initial_progress
-                                - (((now - started_at).as_millis() as f64)
-                                    / (backward_duration_ms as f64))
-                                    as f32)
-                                .clamp(0.0, 1.0);
-                        }
-                    },
-                    AnimationEffect::EaseOut => match self.direction {
-                        AnimationDirection::Forward => {
-                            self.animation_progress = (self.initial_progress
-                                + ease_out_cubic(
-                                    ((now - started_at).as_millis() as f32)
-                                        / (forward_duration_ms as f32),
-                                ))
-                            .clamp(0.0, 1.0);
-                        }
-                        AnimationDirection::Backward => {
-                            self.animation_progress = (self.initial_progress
-                                - ease_out_cubic(
-                                    ((now - started_at).as_millis() as f32)
-                                        / (backward_duration_ms as f32),
-                                ))
-                            .clamp(0.0, 1.0);
-                        }
-                    },
-                    AnimationEffect::None => {}
-                }
+            } else if self.effect != AnimationEffect::None && duration_ms != 0 {
+                // Evaluate new progress through the effect's easing curve.
+                let delay_ms = match self.direction {
+                    AnimationDirection::Forward => self.forward_delay_ms,
+                    AnimationDirection::Backward => self.backward_delay_ms,
+                };
+                // Progress stays pinned at `initial_progress` until the delay has elapsed.
+                let elapsed_ms = (now - started_at).as_millis() as u32;
+                let eased = eased_delta(self.effect, elapsed_ms, delay_ms, duration_ms);
+                self.animation_progress = match self.direction {
+                    AnimationDirection::Forward => (self.initial_progress + eased).clamp(0.0, 1.0),
+                    AnimationDirection::Backward => (self.initial_progress - eased).clamp(0.0, 1.0),
+                };
+            }
+
+            // The first frame that reaches the terminal value for this direction completes it.
+            let reached_terminal = match self.direction {
+                AnimationDirection::Forward => self.animation_progress >= 1.0,
+                AnimationDirection::Backward => self.animation_progress <= 0.0,
+            };
+            if reached_terminal {
+                // `started_at` stays `Some` even once forward completes, so a later cursor leave
+                // is still recognized as a reversal by `on_cursor_moved_update`. Only report the
+                // transition into `Completed` once, instead of on every subsequent redraw.
+                let just_completed = self.state != AnimationState::Completed;
+                self.state = AnimationState::Completed;
+                return just_completed;
             }
             return true;
         }
@@ -133,14 +522,16 @@ impl HoverPressedAnimation {
                     self.direction = AnimationDirection::Forward;
                     // Start from where the animation was at
                     self.initial_progress = self.animation_progress;
-                    self.started_at = Some(std::time::Instant::now());
+                    self.started_at = Some(Instant::now());
+                    self.state = AnimationState::Running;
                 }
             } else {
                 // Start the animation
                 self.direction = AnimationDirection::Forward;
-                self.started_at = Some(std::time::Instant::now());
+                self.started_at = Some(Instant::now());
                 self.animation_progress = 0.0;
                 self.initial_progress = 0.0;
+                self.state = AnimationState::Running;
             }
             self.animation_progress != 1.0
         } else if self.started_at.is_some() {
@@ -151,7 +542,8 @@ impl HoverPressedAnimation {
                     self.direction = AnimationDirection::Backward;
                     // Start from where the animation was at
                     self.initial_progress = self.animation_progress;
-                    self.started_at = Some(std::time::Instant::now());
+                    self.started_at = Some(Instant::now());
+                    self.state = AnimationState::Running;
                     true
                 }
                 AnimationDirection::Backward => true,
@@ -163,25 +555,28 @@ impl HoverPressedAnimation {
 
     /// Start the animation when pressed.
     pub fn on_press(&mut self) {
-        self.started_at = Some(std::time::Instant::now());
+        self.started_at = Some(Instant::now());
         self.direction = AnimationDirection::Forward;
         self.animation_progress = 0.0;
         self.initial_progress = 0.0;
+        self.state = AnimationState::Running;
     }
 
     /// End the animation when released.
     pub fn on_released(&mut self) {
-        self.started_at = Some(std::time::Instant::now());
+        self.started_at = Some(Instant::now());
         self.direction = AnimationDirection::Backward;
         self.initial_progress = self.animation_progress;
+        self.state = AnimationState::Running;
     }
 
     /// End the animation (go backgwards), skipping the forward phase.
     pub fn on_activate(&mut self) {
-        self.started_at = Some(std::time::Instant::now());
+        self.started_at = Some(Instant::now());
         self.direction = AnimationDirection::Backward;
         self.initial_progress = 1.0;
         self.animation_progress = 1.0;
+        self.state = AnimationState::Running;
     }
 }
 
@@ -191,6 +586,25 @@ fn ease_out_cubic(t: f32) -> f32 {
     p * p * p + 1f32
 }
 
+/// Based on Robert Penner's infamous easing equations, MIT license.
+fn ease_out_bounce(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984_375
+    }
+}
+
 /// Mix with another color with the given ratio (should be in `iced/core/src/color.rs` ?)
 pub fn mix(mut color: Color, other: Color, ratio: f32) -> Color {
     let self_ratio = 1.0 - ratio;
@@ -199,4 +613,304 @@ pub fn mix(mut color: Color, other: Color, ratio: f32) -> Color {
     color.b = (color.b * self_ratio + other.b * ratio).clamp(0.0, 1.0);
     color.a = (color.a * self_ratio + other.a * ratio).clamp(0.0, 1.0);
     color
-}
\ No newline at end of file
+}
+
+/// Scale a [`Duration`] by a floating-point multiplier, such as a theme's `animation_multiplier`.
+///
+/// The float-to-int conversion saturates rather than overflowing, so an extreme multiplier
+/// clamps to `u64::MAX` milliseconds instead of wrapping. Negative multipliers count as `0.0`.
+pub fn saturating_scale(duration: Duration, multiplier: f32) -> Duration {
+    let millis = duration.as_millis() as f64 * f64::from(multiplier.max(0.0));
+    Duration::from_millis(millis as u64)
+}
+
+/// Outcome of polling a [`HoldToConfirm`] animation on a redraw request.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HoldOutcome {
+    /// Nothing is happening: the hold was never started, or a previous `Confirmed`/`Cancelled`
+    /// has already been reported and the hold has since gone idle.
+    #[default]
+    Idle,
+    /// The hold (or its release) is still in progress, carrying the current progress.
+    InProgress(f32),
+    /// The hold reached 1.0 while the button was held: the action is confirmed.
+    Confirmed,
+    /// The button was released before the hold completed, and progress has eased back to 0.0.
+    Cancelled,
+}
+
+/// Hold-to-confirm animation for "press and hold to perform destructive action" interactions.
+///
+/// Progress advances from 0.0 to 1.0 while the button is physically held, reusing
+/// [`AnimationEffect`] for the easing curve. Releasing early eases the progress back down to
+/// 0.0 instead of confirming; only reaching 1.0 while still held fires a confirmation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HoldToConfirm {
+    /// Animation direction: forward means the hold is filling up towards confirmation
+    pub direction: AnimationDirection,
+    /// The instant the current phase (hold or release) started at (`None` if idle)
+    pub started_at: Option<Instant>,
+    /// The progress of the hold, between 0.0 (not held) and 1.0 (confirmed)
+    pub progress: f32,
+    /// The progress the current phase started from
+    pub initial_progress: f32,
+    /// The type of effect used for both the hold and release-back transitions
+    pub effect: AnimationEffect,
+}
+
+impl HoldToConfirm {
+    /// Create a hold-to-confirm animation with the given transition effect.
+    pub fn new(effect: AnimationEffect) -> Self {
+        Self {
+            effect,
+            ..Default::default()
+        }
+    }
+
+    /// Check if the button is currently held or easing back from an early release.
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Start filling up the hold progress.
+    pub fn on_hold_start(&mut self, now: Instant) {
+        self.direction = AnimationDirection::Forward;
+        self.started_at = Some(now);
+        self.progress = 0.0;
+        self.initial_progress = 0.0;
+    }
+
+    /// Release the button early: ease the progress back down to 0.0 instead of confirming.
+    ///
+    /// Does nothing if the hold isn't running, or already confirmed.
+    pub fn on_hold_release(&mut self, now: Instant) {
+        if self.started_at.is_none() {
+            return;
+        }
+        if self.direction == AnimationDirection::Forward && self.progress < 1.0 {
+            self.direction = AnimationDirection::Backward;
+            self.initial_progress = self.progress;
+            self.started_at = Some(now);
+        }
+    }
+
+    /// Update the hold progress, if necessary, and return the current outcome.
+    pub fn on_redraw_request_update(
+        &mut self,
+        hold_duration_ms: u32,
+        release_duration_ms: u32,
+        now: Instant,
+    ) -> HoldOutcome {
+        let Some(started_at) = self.started_at else {
+            return HoldOutcome::Idle;
+        };
+
+        let duration_ms = match self.direction {
+            AnimationDirection::Forward => hold_duration_ms,
+            AnimationDirection::Backward => release_duration_ms,
+        };
+        if duration_ms == 0 || self.effect == AnimationEffect::None {
+            // Instantaneous: jump straight to the terminal value for this direction.
+            self.progress = match self.direction {
+                AnimationDirection::Forward => 1.0,
+                AnimationDirection::Backward => 0.0,
+            };
+        } else {
+            let elapsed_ms = (now - started_at).as_millis() as u32;
+            let eased = eased_delta(self.effect, elapsed_ms, 0, duration_ms);
+            self.progress = match self.direction {
+                AnimationDirection::Forward => (self.initial_progress + eased).clamp(0.0, 1.0),
+                AnimationDirection::Backward => (self.initial_progress - eased).clamp(0.0, 1.0),
+            };
+        }
+
+        match self.direction {
+            AnimationDirection::Forward if self.progress >= 1.0 => {
+                self.started_at = None;
+                HoldOutcome::Confirmed
+            }
+            AnimationDirection::Backward if self.progress <= 0.0 => {
+                self.started_at = None;
+                HoldOutcome::Cancelled
+            }
+            _ => HoldOutcome::InProgress(self.progress),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Animation, AnimationDirection, AnimationEffect, AnimationState, Duration, HoldOutcome,
+        HoldToConfirm, HoverPressedAnimation, Instant,
+    };
+
+    const VARIANTS: &[AnimationEffect] = &[
+        AnimationEffect::Linear,
+        AnimationEffect::EaseOut,
+        AnimationEffect::None,
+        AnimationEffect::EaseInQuad,
+        AnimationEffect::EaseOutQuad,
+        AnimationEffect::EaseInOutQuad,
+        AnimationEffect::EaseInCubic,
+        AnimationEffect::EaseOutCubic,
+        AnimationEffect::EaseInOutCubic,
+        AnimationEffect::EaseInQuart,
+        AnimationEffect::EaseOutQuart,
+        AnimationEffect::EaseInOutQuart,
+        AnimationEffect::EaseInQuint,
+        AnimationEffect::EaseOutQuint,
+        AnimationEffect::EaseInOutQuint,
+        AnimationEffect::EaseInSine,
+        AnimationEffect::EaseOutSine,
+        AnimationEffect::EaseInOutSine,
+        AnimationEffect::EaseInExpo,
+        AnimationEffect::EaseOutExpo,
+        AnimationEffect::EaseInOutExpo,
+        AnimationEffect::EaseInCirc,
+        AnimationEffect::EaseOutCirc,
+        AnimationEffect::EaseInOutCirc,
+        AnimationEffect::EaseInBack,
+        AnimationEffect::EaseOutBack,
+        AnimationEffect::EaseInOutBack,
+        AnimationEffect::EaseInElastic,
+        AnimationEffect::EaseOutElastic,
+        AnimationEffect::EaseInOutElastic,
+        AnimationEffect::EaseInBounce,
+        AnimationEffect::EaseOutBounce,
+        AnimationEffect::EaseInOutBounce,
+    ];
+
+    fn approx_eq(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn every_effect_starts_at_zero_and_ends_at_one() {
+        for effect in VARIANTS {
+            assert!(
+                approx_eq(effect.apply(0.0), 0.0),
+                "{effect:?}.apply(0.0) should be ~0.0"
+            );
+            assert!(
+                approx_eq(effect.apply(1.0), 1.0),
+                "{effect:?}.apply(1.0) should be ~1.0"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_clamps_out_of_range_progress() {
+        for effect in VARIANTS {
+            assert!(approx_eq(effect.apply(-1.0), effect.apply(0.0)));
+            assert!(approx_eq(effect.apply(2.0), effect.apply(1.0)));
+        }
+    }
+
+    #[test]
+    fn zero_duration_snaps_to_target_without_nan() {
+        let now = Instant::now();
+        let anim = Animation::new(0.0_f32, 10.0_f32, 0, AnimationEffect::Linear, now);
+        assert_eq!(anim.value(now), 10.0);
+        assert!(anim.is_finished(now));
+    }
+
+    #[test]
+    fn ease_to_retargets_from_the_current_value_at_the_given_instant() {
+        let now = Instant::now();
+        let mut anim = Animation::new(0.0_f32, 10.0_f32, 100, AnimationEffect::Linear, now);
+        let halfway = now + Duration::from_millis(50);
+        anim.ease_to(20.0, halfway);
+        assert_eq!(anim.from, 5.0);
+        assert_eq!(anim.to, 20.0);
+        assert_eq!(anim.started_at, Some(halfway));
+    }
+
+    #[test]
+    fn forward_zero_duration_with_delay_snaps_without_nan() {
+        let now = Instant::now();
+        let mut anim = HoverPressedAnimation {
+            direction: AnimationDirection::Forward,
+            started_at: Some(now),
+            animation_progress: 0.0,
+            initial_progress: 0.0,
+            effect: AnimationEffect::Linear,
+            state: AnimationState::Running,
+            forward_delay_ms: 50,
+            backward_delay_ms: 0,
+        };
+        assert!(anim.on_redraw_request_update(0, 200, now));
+        assert_eq!(anim.animation_progress, 1.0);
+    }
+
+    #[test]
+    fn fresh_hold_to_confirm_reports_idle_not_cancelled() {
+        let now = Instant::now();
+        let mut hold = HoldToConfirm::new(AnimationEffect::Linear);
+        assert_eq!(
+            hold.on_redraw_request_update(200, 200, now),
+            HoldOutcome::Idle
+        );
+    }
+
+    #[test]
+    fn confirmed_is_reported_once_then_goes_idle() {
+        let now = Instant::now();
+        let mut hold = HoldToConfirm::new(AnimationEffect::Linear);
+        hold.on_hold_start(now);
+        let done = now + Duration::from_millis(200);
+        assert_eq!(
+            hold.on_redraw_request_update(200, 200, done),
+            HoldOutcome::Confirmed
+        );
+        assert_eq!(
+            hold.on_redraw_request_update(200, 200, done),
+            HoldOutcome::Idle
+        );
+    }
+
+    #[test]
+    fn pause_freezes_progress_and_resume_continues_from_there() {
+        let now = Instant::now();
+        let mut anim = HoverPressedAnimation {
+            started_at: Some(now),
+            state: AnimationState::Running,
+            effect: AnimationEffect::Linear,
+            ..Default::default()
+        };
+
+        let mid = now + Duration::from_millis(50);
+        anim.on_redraw_request_update(100, 100, mid);
+        assert_eq!(anim.animation_progress, 0.5);
+
+        anim.pause(mid);
+        assert_eq!(anim.state, AnimationState::Paused);
+        assert!(anim.started_at.is_none());
+        assert_eq!(anim.initial_progress, 0.5);
+
+        let resume_at = mid + Duration::from_millis(10);
+        anim.resume(resume_at);
+        assert_eq!(anim.state, AnimationState::Running);
+        assert_eq!(anim.started_at, Some(resume_at));
+
+        let later = resume_at + Duration::from_millis(50);
+        anim.on_redraw_request_update(100, 100, later);
+        assert_eq!(anim.animation_progress, 1.0);
+    }
+
+    #[test]
+    fn completed_transition_is_reported_only_once() {
+        let now = Instant::now();
+        let mut anim = HoverPressedAnimation {
+            started_at: Some(now),
+            state: AnimationState::Running,
+            effect: AnimationEffect::Linear,
+            ..Default::default()
+        };
+
+        let done = now + Duration::from_millis(100);
+        assert!(anim.on_redraw_request_update(100, 100, done));
+        assert_eq!(anim.state, AnimationState::Completed);
+        assert!(!anim.on_redraw_request_update(100, 100, done));
+    }
+}
@@ -5,6 +5,7 @@ pub use derivation::*;
 pub use mode::*;
 pub use spacing::*;
 pub use theme::*;
+pub use time::*;
 
 mod animation;
 mod corner;
@@ -13,3 +14,4 @@ mod derivation;
 mod mode;
 mod spacing;
 mod theme;
+mod time;
@@ -0,0 +1,14 @@
+//! Platform time abstraction so animations stay usable on `wasm32-unknown-unknown`.
+//!
+//! `std::time::Instant` panics when used on the web, so native targets re-export it as-is while
+//! `wasm32` targets delegate to the `web-time` crate, mirroring `iced_core::time`.
+//!
+//! The `wasm32` branch below requires `web-time` to be listed as a dependency (target-gated on
+//! `cfg(target_arch = "wasm32")`) in this crate's `Cargo.toml`; without that entry the `wasm32`
+//! build of this module fails to resolve the crate.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Duration, Instant};
+
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Duration, Instant};